@@ -1,13 +1,16 @@
 use crate::sdk;
-use crate::sdk::FungibleTokenResponse;
+use crate::sdk::{FungibleTokenResponse, MsgIssueResponse, NFTClassResponse, NFTResponse};
 use cosmwasm_std::{
-    entry_point, to_binary, Binary, Deps, QueryRequest, Reply, ReplyOn, StdResult, SubMsg,
+    entry_point, to_binary, BankMsg, BankQuery, Binary, Coin, Decimal, Deps, Fraction,
+    QueryRequest, Reply, StdResult, SubMsg, SubMsgResult, Uint256,
 };
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdError, Uint128};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, StdError, Uint128};
 use cw2::set_contract_version;
-use cw_storage_plus::Item;
+use cw_storage_plus::{Item, Map};
+use prost::Message;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use thiserror::Error;
 
 // Flow of the smart contract:
@@ -28,14 +31,95 @@ pub struct State {
 
 pub const STATE: Item<State> = Item::new("state");
 
+// Address set at instantiation, allowed to call the asset-ft admin messages below
+// (mint/burn/freeze/unfreeze/whitelist). Anyone else gets `ContractError::Unauthorized`.
+pub const OWNER: Item<Addr> = Item::new("owner");
+
+// Reply ids for the asset-nft submessages, confirmed the same way as the asset-ft ones but
+// without denom/rate bookkeeping since there's nothing to reconstruct for them.
+const ISSUE_CLASS_REPLY_ID: u64 = 3;
+const MINT_NFT_REPLY_ID: u64 = 4;
+
+// Reply id for the `AssetFTMsgIssue` submessage that creates a pool's LP-share denom.
+const POOL_SHARE_REPLY_ID: u64 = 5;
+
+// Reply ids below this are reserved for the fixed ids above; every `AssetFTMsgIssue`
+// submessage fired by `issue_tokens` gets a fresh id at or above it from
+// `NEXT_ISSUE_REPLY_ID`, so two `Issue` calls never clobber each other's pending/issued
+// bookkeeping.
+const FIRST_ISSUE_REPLY_ID: u64 = 100;
+
+// Next reply id to hand out to an `AssetFTMsgIssue` submessage; advanced by `issue_tokens`.
+pub const NEXT_ISSUE_REPLY_ID: Item<u64> = Item::new("next_issue_reply_id");
+
+// Swap fee denominator: `fee_bps` is expressed in basis points out of 10000.
+const FEE_BPS_DENOMINATOR: u128 = 10_000;
+
+// Subunit pending for each in-flight issue reply, so that `reply` can reconstruct the
+// denom deterministically if the submessage response carries no usable `data`.
+pub const PENDING_SUBUNITS: Map<u64, String> = Map::new("pending_subunits");
+
+// Denoms of the tokens issued so far by `issue_tokens`, keyed by the reply id they were
+// confirmed under. Reply ids are never reused, so this is an append-only record of those
+// denoms. A pool's LP-share denom is not recorded here; it is kept in `POOL`.
+pub const ISSUED_DENOMS: Map<u64, String> = Map::new("issued_denoms");
+
+// Burn-rate and send-commission-rate charged by the `asset` module on every transfer of a
+// given denom, applied as a fraction of the amount transferred.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TransferRates {
+    pub burn_rate: Decimal,
+    pub send_commission_rate: Decimal,
+}
+
+// Rates pending for each in-flight issue reply, moved to `TRANSFER_RATES` once the denom is known.
+pub const PENDING_RATES: Map<u64, TransferRates> = Map::new("pending_rates");
+
+pub const TRANSFER_RATES: Map<&str, TransferRates> = Map::new("transfer_rates");
+
+// Compressed secp256k1 public key of the off-chain authority allowed to authorize mints via
+// `ExecuteMsg::MintSigned`.
+pub const AUTHORITY_PUBKEY: Item<Binary> = Item::new("authority_pubkey");
+
+// Nonces already consumed by a `MintSigned` call, so a captured signature can't be replayed.
+pub const USED_NONCES: Map<u64, ()> = Map::new("used_nonces");
+
+// A minimal constant-product pool between two denoms, backed by an asset-ft share denom
+// minted/burned by the contract itself. The contract manages a single pool.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Pool {
+    pub denom_a: String,
+    pub denom_b: String,
+    pub share_denom: String,
+    pub reserve_a: Uint128,
+    pub reserve_b: Uint128,
+    pub total_shares: Uint128,
+    pub fee_bps: u64,
+}
+
+pub const POOL: Item<Pool> = Item::new("pool");
+
+// Pair/fee pending while the share denom issuance submessage is in flight.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingPool {
+    pub denom_a: String,
+    pub denom_b: String,
+    pub fee_bps: u64,
+}
+
+pub const PENDING_POOL: Item<PendingPool> = Item::new("pending_pool");
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
-    _msg: InstantiateMsg,
+    msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    AUTHORITY_PUBKEY.save(deps.storage, &msg.authority_pubkey)?;
+    OWNER.save(deps.storage, &info.sender)?;
+    NEXT_ISSUE_REPLY_ID.save(deps.storage, &FIRST_ISSUE_REPLY_ID)?;
 
     Ok(Response::new()
         .add_attribute("method", "instantiate")
@@ -46,7 +130,7 @@ pub fn instantiate(
 pub fn execute(
     deps: DepsMut,
     _env: Env,
-    _info: MessageInfo,
+    info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response<sdk::Messages>, ContractError> {
     match msg {
@@ -55,41 +139,148 @@ pub fn execute(
             subunit,
             precision,
             amount,
-        } => issue_tokens(deps, symbol, subunit, precision, amount),
+            features,
+            burn_rate,
+            send_commission_rate,
+        } => issue_tokens(
+            deps,
+            symbol,
+            subunit,
+            precision,
+            amount,
+            features,
+            burn_rate,
+            send_commission_rate,
+        ),
+        ExecuteMsg::Mint { denom, amount } => mint_tokens(deps, info, denom, amount),
+        ExecuteMsg::Burn { denom, amount } => burn_tokens(deps, info, denom, amount),
+        ExecuteMsg::Freeze {
+            account,
+            denom,
+            amount,
+        } => freeze_tokens(deps, info, account, denom, amount),
+        ExecuteMsg::Unfreeze {
+            account,
+            denom,
+            amount,
+        } => unfreeze_tokens(deps, info, account, denom, amount),
+        ExecuteMsg::GloballyFreeze { denom } => globally_freeze_token(deps, info, denom),
+        ExecuteMsg::GloballyUnfreeze { denom } => globally_unfreeze_token(deps, info, denom),
+        ExecuteMsg::SetWhitelistedLimit {
+            account,
+            denom,
+            amount,
+        } => set_whitelisted_limit(deps, info, account, denom, amount),
+        ExecuteMsg::Send { to, denom, amount } => send_tokens(deps, info, to, denom, amount),
+        ExecuteMsg::MultiSend { outputs } => multi_send_tokens(deps, info, outputs),
+        ExecuteMsg::MintSigned {
+            recipient,
+            denom,
+            amount,
+            nonce,
+            signature,
+        } => mint_signed(deps, recipient, denom, amount, nonce, signature),
+        ExecuteMsg::IssueClass {
+            symbol,
+            name,
+            description,
+            uri,
+            features,
+        } => issue_nft_class(deps, info, symbol, name, description, uri, features),
+        ExecuteMsg::MintNFT {
+            class_id,
+            id,
+            uri,
+            data,
+        } => mint_nft(deps, info, class_id, id, uri, data),
+        ExecuteMsg::BurnNFT { class_id, id } => burn_nft(deps, info, class_id, id),
+        ExecuteMsg::FreezeNFT { class_id, id } => freeze_nft(deps, info, class_id, id),
+        ExecuteMsg::CreatePool {
+            denom_a,
+            denom_b,
+            share_symbol,
+            share_subunit,
+            fee_bps,
+        } => create_pool(deps, denom_a, denom_b, share_symbol, share_subunit, fee_bps),
+        ExecuteMsg::ProvideLiquidity { assets } => provide_liquidity(deps, info, assets),
+        ExecuteMsg::WithdrawLiquidity { shares } => withdraw_liquidity(deps, info, shares),
+        ExecuteMsg::Swap { offer } => swap(deps, info, offer),
     }
 }
 
+// next_issue_reply_id hands out the next unique id in the `AssetFTMsgIssue` reply id space,
+// advancing the counter so no two issuances (or the two submessages within one) ever share
+// an id.
+fn next_issue_reply_id(storage: &mut dyn cosmwasm_std::Storage) -> Result<u64, ContractError> {
+    let id = NEXT_ISSUE_REPLY_ID.load(storage)?;
+    NEXT_ISSUE_REPLY_ID.save(storage, &(id + 1))?;
+    Ok(id)
+}
+
 fn issue_tokens(
     deps: DepsMut,
     symbol: String,
     subunit: String,
     precision: u32,
     amount: Uint128,
+    features: Vec<u32>,
+    burn_rate: Decimal,
+    send_commission_rate: Decimal,
 ) -> Result<Response<sdk::Messages>, ContractError> {
     if amount == Uint128::zero() {
         return Err(ContractError::InvalidZeroAmount {});
     }
+    if burn_rate + send_commission_rate > Decimal::one() {
+        return Err(ContractError::InvalidRate {});
+    }
 
     let state = State { count: 0 };
     STATE.save(deps.storage, &state)?;
 
     // Send two submessages handled by the asset module to create two fungible tokens.
-    // ReplyOn::Always means that we want `reply` to be called after each submessage execution.
-    let mut msg1 = SubMsg::new(sdk::Messages::AssetFTMsgIssue {
-        symbol: symbol.clone() + "1",
-        subunit: subunit.clone() + "1",
-        precision,
-        initial_amount: amount,
-    });
-    msg1.reply_on = ReplyOn::Always;
+    // `reply_always` means that we want `reply` to be called after each submessage execution.
+    // Each gets a fresh reply id from `NEXT_ISSUE_REPLY_ID`, so `reply` can tell the two
+    // callbacks apart and look up the subunit/rates each one belongs to, and a later `Issue`
+    // call never clobbers an earlier one's bookkeeping.
+    let reply_id_1 = next_issue_reply_id(deps.storage)?;
+    let reply_id_2 = next_issue_reply_id(deps.storage)?;
 
-    let mut msg2 = SubMsg::new(sdk::Messages::AssetFTMsgIssue {
-        symbol: symbol.clone() + "2",
-        subunit: subunit.clone() + "2",
-        precision,
-        initial_amount: amount,
-    });
-    msg2.reply_on = ReplyOn::Always;
+    let subunit1 = subunit.clone() + "1";
+    let subunit2 = subunit.clone() + "2";
+    PENDING_SUBUNITS.save(deps.storage, reply_id_1, &subunit1)?;
+    PENDING_SUBUNITS.save(deps.storage, reply_id_2, &subunit2)?;
+    let rates = TransferRates {
+        burn_rate,
+        send_commission_rate,
+    };
+    PENDING_RATES.save(deps.storage, reply_id_1, &rates)?;
+    PENDING_RATES.save(deps.storage, reply_id_2, &rates)?;
+
+    let msg1 = SubMsg::reply_always(
+        sdk::Messages::AssetFTMsgIssue {
+            symbol: symbol.clone() + "1",
+            subunit: subunit1,
+            precision,
+            initial_amount: amount,
+            features: features.clone(),
+            burn_rate,
+            send_commission_rate,
+        },
+        reply_id_1,
+    );
+
+    let msg2 = SubMsg::reply_always(
+        sdk::Messages::AssetFTMsgIssue {
+            symbol: symbol.clone() + "2",
+            subunit: subunit2,
+            precision,
+            initial_amount: amount,
+            features,
+            burn_rate,
+            send_commission_rate,
+        },
+        reply_id_2,
+    );
 
     // As a part of the response we send two submessages which are then forwarded to the parser
     // in go.
@@ -102,8 +293,581 @@ fn issue_tokens(
     Ok(res)
 }
 
+// The functions below forward the asset-ft admin messages as native submessages. They are
+// plain forwards (no reply tracking) since the chain executes them synchronously and surfaces
+// any failure as the submessage's own error.
+
+// mint_signed lets an off-chain relayer authorize a mint without holding the mint key
+// on-chain: it presents a signature over the mint parameters from the authority key stored
+// at instantiation, and a nonce that can only be consumed once.
+fn mint_signed(
+    deps: DepsMut,
+    recipient: String,
+    denom: String,
+    amount: Uint128,
+    nonce: u64,
+    signature: Binary,
+) -> Result<Response<sdk::Messages>, ContractError> {
+    if USED_NONCES.has(deps.storage, nonce) {
+        return Err(ContractError::NonceAlreadyUsed {});
+    }
+
+    let pubkey = AUTHORITY_PUBKEY.load(deps.storage)?;
+    let hash = mint_signed_hash(&recipient, &denom, amount, nonce);
+    let verified = deps
+        .api
+        .secp256k1_verify(&hash, &signature, &pubkey)
+        .map_err(|_| ContractError::InvalidSignature {})?;
+    if !verified {
+        return Err(ContractError::InvalidSignature {});
+    }
+
+    USED_NONCES.save(deps.storage, nonce, &())?;
+
+    Ok(Response::new()
+        .add_attribute("method", "mint_signed")
+        .add_attribute("recipient", recipient.clone())
+        .add_attribute("denom", denom.clone())
+        .add_attribute("amount", amount)
+        .add_message(sdk::Messages::AssetFTMsgMint {
+            denom: denom.clone(),
+            amount,
+        })
+        .add_message(BankMsg::Send {
+            to_address: recipient,
+            amount: vec![Coin { denom, amount }],
+        }))
+}
+
+// mint_signed_hash length-prefixes `recipient` and `denom` before hashing so that, e.g.,
+// ("ab", "cdef") and ("abcd", "ef") can't collide to the same signed message.
+fn mint_signed_hash(recipient: &str, denom: &str, amount: Uint128, nonce: u64) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update((recipient.len() as u32).to_be_bytes());
+    hasher.update(recipient.as_bytes());
+    hasher.update((denom.len() as u32).to_be_bytes());
+    hasher.update(denom.as_bytes());
+    hasher.update(amount.u128().to_be_bytes());
+    hasher.update(nonce.to_be_bytes());
+    hasher.finalize().to_vec()
+}
+
+// assert_owner rejects the call unless `info.sender` is the address that instantiated the
+// contract. Every asset-ft admin message below is gated on this, since forwarding them
+// unchecked would let any address mint/burn/freeze tokens the contract merely issued on
+// someone else's behalf.
+fn assert_owner(deps: &DepsMut, info: &MessageInfo) -> Result<(), ContractError> {
+    if OWNER.load(deps.storage)? != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+fn mint_tokens(
+    deps: DepsMut,
+    info: MessageInfo,
+    denom: String,
+    amount: Uint128,
+) -> Result<Response<sdk::Messages>, ContractError> {
+    assert_owner(&deps, &info)?;
+    Ok(Response::new()
+        .add_attribute("method", "mint_tokens")
+        .add_attribute("denom", denom.clone())
+        .add_attribute("amount", amount)
+        .add_message(sdk::Messages::AssetFTMsgMint { denom, amount }))
+}
+
+fn burn_tokens(
+    deps: DepsMut,
+    info: MessageInfo,
+    denom: String,
+    amount: Uint128,
+) -> Result<Response<sdk::Messages>, ContractError> {
+    assert_owner(&deps, &info)?;
+    Ok(Response::new()
+        .add_attribute("method", "burn_tokens")
+        .add_attribute("denom", denom.clone())
+        .add_attribute("amount", amount)
+        .add_message(sdk::Messages::AssetFTMsgBurn { denom, amount }))
+}
+
+fn freeze_tokens(
+    deps: DepsMut,
+    info: MessageInfo,
+    account: String,
+    denom: String,
+    amount: Uint128,
+) -> Result<Response<sdk::Messages>, ContractError> {
+    assert_owner(&deps, &info)?;
+    Ok(Response::new()
+        .add_attribute("method", "freeze_tokens")
+        .add_attribute("account", account.clone())
+        .add_attribute("denom", denom.clone())
+        .add_attribute("amount", amount)
+        .add_message(sdk::Messages::AssetFTMsgFreeze {
+            account,
+            denom,
+            amount,
+        }))
+}
+
+fn unfreeze_tokens(
+    deps: DepsMut,
+    info: MessageInfo,
+    account: String,
+    denom: String,
+    amount: Uint128,
+) -> Result<Response<sdk::Messages>, ContractError> {
+    assert_owner(&deps, &info)?;
+    Ok(Response::new()
+        .add_attribute("method", "unfreeze_tokens")
+        .add_attribute("account", account.clone())
+        .add_attribute("denom", denom.clone())
+        .add_attribute("amount", amount)
+        .add_message(sdk::Messages::AssetFTMsgUnfreeze {
+            account,
+            denom,
+            amount,
+        }))
+}
+
+fn globally_freeze_token(
+    deps: DepsMut,
+    info: MessageInfo,
+    denom: String,
+) -> Result<Response<sdk::Messages>, ContractError> {
+    assert_owner(&deps, &info)?;
+    Ok(Response::new()
+        .add_attribute("method", "globally_freeze_token")
+        .add_attribute("denom", denom.clone())
+        .add_message(sdk::Messages::AssetFTMsgGloballyFreeze { denom }))
+}
+
+fn globally_unfreeze_token(
+    deps: DepsMut,
+    info: MessageInfo,
+    denom: String,
+) -> Result<Response<sdk::Messages>, ContractError> {
+    assert_owner(&deps, &info)?;
+    Ok(Response::new()
+        .add_attribute("method", "globally_unfreeze_token")
+        .add_attribute("denom", denom.clone())
+        .add_message(sdk::Messages::AssetFTMsgGloballyUnfreeze { denom }))
+}
+
+fn set_whitelisted_limit(
+    deps: DepsMut,
+    info: MessageInfo,
+    account: String,
+    denom: String,
+    amount: Uint128,
+) -> Result<Response<sdk::Messages>, ContractError> {
+    assert_owner(&deps, &info)?;
+    Ok(Response::new()
+        .add_attribute("method", "set_whitelisted_limit")
+        .add_attribute("account", account.clone())
+        .add_attribute("denom", denom.clone())
+        .add_attribute("amount", amount)
+        .add_message(sdk::Messages::AssetFTMsgSetWhitelistedLimit {
+            account,
+            denom,
+            amount,
+        }))
+}
+
+// transfer_fees computes the burn and send-commission portions of `amount` for the given
+// rates. Both are integer-truncated toward zero via `multiply_ratio` rather than going
+// through floating-point-like Decimal multiplication, so the math can't round a fee up past
+// what the chain actually charges.
+fn transfer_fees(amount: Uint128, rates: &TransferRates) -> (Uint128, Uint128) {
+    let burn = amount.multiply_ratio(rates.burn_rate.numerator(), rates.burn_rate.denominator());
+    let commission = amount.multiply_ratio(
+        rates.send_commission_rate.numerator(),
+        rates.send_commission_rate.denominator(),
+    );
+    (burn, commission)
+}
+
+// send_tokens lets the contract, as signer, distribute a token it controls. Coreum applies
+// burn-rate and send-commission on transfer, so the amount that actually arrives at `to` can
+// be less than `amount`; the gross amount sent is surfaced as an attribute so integrators can
+// reconcile it against the recipient's observed balance.
+fn send_tokens(
+    deps: DepsMut,
+    info: MessageInfo,
+    to: String,
+    denom: String,
+    amount: Uint128,
+) -> Result<Response<sdk::Messages>, ContractError> {
+    assert_owner(&deps, &info)?;
+    Ok(Response::new()
+        .add_attribute("method", "send_tokens")
+        .add_attribute("to", to.clone())
+        .add_attribute("denom", denom.clone())
+        .add_attribute("gross_amount", amount)
+        .add_message(BankMsg::Send {
+            to_address: to,
+            amount: vec![Coin { denom, amount }],
+        }))
+}
+
+fn multi_send_tokens(
+    deps: DepsMut,
+    info: MessageInfo,
+    outputs: Vec<(String, Coin)>,
+) -> Result<Response<sdk::Messages>, ContractError> {
+    assert_owner(&deps, &info)?;
+    let messages = outputs
+        .into_iter()
+        .map(|(to, coin)| BankMsg::Send {
+            to_address: to,
+            amount: vec![coin],
+        })
+        .collect::<Vec<_>>();
+
+    Ok(Response::new()
+        .add_attribute("method", "multi_send_tokens")
+        .add_messages(messages))
+}
+
+// The functions below mirror the asset-ft admin forwards above, but for the asset-nft module.
+// `issue_nft_class` and `mint_nft` are tracked through `reply` the same way token issuance is,
+// so the contract can confirm the class/NFT was actually created; burn and freeze are plain
+// forwards like their fungible counterparts.
+
+fn issue_nft_class(
+    deps: DepsMut,
+    info: MessageInfo,
+    symbol: String,
+    name: String,
+    description: String,
+    uri: String,
+    features: Vec<u32>,
+) -> Result<Response<sdk::Messages>, ContractError> {
+    assert_owner(&deps, &info)?;
+    let msg = SubMsg::reply_always(
+        sdk::Messages::AssetNFTMsgIssueClass {
+            symbol: symbol.clone(),
+            name,
+            description,
+            uri,
+            features,
+        },
+        ISSUE_CLASS_REPLY_ID,
+    );
+
+    Ok(Response::new()
+        .add_attribute("method", "issue_nft_class")
+        .add_attribute("symbol", symbol)
+        .add_submessage(msg))
+}
+
+fn mint_nft(
+    deps: DepsMut,
+    info: MessageInfo,
+    class_id: String,
+    id: String,
+    uri: String,
+    data: Option<Binary>,
+) -> Result<Response<sdk::Messages>, ContractError> {
+    assert_owner(&deps, &info)?;
+    let msg = SubMsg::reply_always(
+        sdk::Messages::AssetNFTMsgMint {
+            class_id: class_id.clone(),
+            id: id.clone(),
+            uri,
+            data,
+        },
+        MINT_NFT_REPLY_ID,
+    );
+
+    Ok(Response::new()
+        .add_attribute("method", "mint_nft")
+        .add_attribute("class_id", class_id)
+        .add_attribute("id", id)
+        .add_submessage(msg))
+}
+
+fn burn_nft(
+    deps: DepsMut,
+    info: MessageInfo,
+    class_id: String,
+    id: String,
+) -> Result<Response<sdk::Messages>, ContractError> {
+    assert_owner(&deps, &info)?;
+    Ok(Response::new()
+        .add_attribute("method", "burn_nft")
+        .add_attribute("class_id", class_id.clone())
+        .add_attribute("id", id.clone())
+        .add_message(sdk::Messages::AssetNFTMsgBurn { class_id, id }))
+}
+
+fn freeze_nft(
+    deps: DepsMut,
+    info: MessageInfo,
+    class_id: String,
+    id: String,
+) -> Result<Response<sdk::Messages>, ContractError> {
+    assert_owner(&deps, &info)?;
+    Ok(Response::new()
+        .add_attribute("method", "freeze_nft")
+        .add_attribute("class_id", class_id.clone())
+        .add_attribute("id", id.clone())
+        .add_message(sdk::Messages::AssetNFTMsgFreeze { class_id, id }))
+}
+
+// create_pool issues the LP-share denom for a new constant-product pool between `denom_a` and
+// `denom_b`. The pool itself is only recorded once `reply` confirms the share denom, since
+// that's the only point at which we know what it's called.
+fn create_pool(
+    deps: DepsMut,
+    denom_a: String,
+    denom_b: String,
+    share_symbol: String,
+    share_subunit: String,
+    fee_bps: u64,
+) -> Result<Response<sdk::Messages>, ContractError> {
+    if fee_bps as u128 > FEE_BPS_DENOMINATOR {
+        return Err(ContractError::CustomError {
+            val: format!("fee_bps must not exceed {FEE_BPS_DENOMINATOR}"),
+        });
+    }
+    if POOL.may_load(deps.storage)?.is_some() || PENDING_POOL.may_load(deps.storage)?.is_some() {
+        return Err(ContractError::CustomError {
+            val: "a pool already exists".to_string(),
+        });
+    }
+
+    PENDING_POOL.save(
+        deps.storage,
+        &PendingPool {
+            denom_a,
+            denom_b,
+            fee_bps,
+        },
+    )?;
+    PENDING_SUBUNITS.save(deps.storage, POOL_SHARE_REPLY_ID, &share_subunit)?;
+
+    let msg = SubMsg::reply_always(
+        sdk::Messages::AssetFTMsgIssue {
+            symbol: share_symbol,
+            subunit: share_subunit,
+            precision: 6,
+            initial_amount: Uint128::zero(),
+            features: vec![],
+            burn_rate: Decimal::zero(),
+            send_commission_rate: Decimal::zero(),
+        },
+        POOL_SHARE_REPLY_ID,
+    );
+
+    Ok(Response::new()
+        .add_attribute("method", "create_pool")
+        .add_submessage(msg))
+}
+
+// assert_funds_match rejects the call unless `info.funds` is exactly the set of coins the
+// message claims to deposit/offer, so a caller can't credit pool state for tokens it never
+// attached.
+fn assert_funds_match(info: &MessageInfo, expected: &[Coin]) -> Result<(), ContractError> {
+    if info.funds.len() != expected.len() {
+        return Err(ContractError::CustomError {
+            val: "attached funds do not match the claimed amounts".to_string(),
+        });
+    }
+    for coin in expected {
+        let sent = info
+            .funds
+            .iter()
+            .find(|f| f.denom == coin.denom)
+            .map(|f| f.amount)
+            .unwrap_or_default();
+        if sent != coin.amount {
+            return Err(ContractError::CustomError {
+                val: format!("expected {} of {} to be attached", coin.amount, coin.denom),
+            });
+        }
+    }
+    Ok(())
+}
+
+// provide_liquidity mints shares for a deposit of both pool assets: `sqrt(amount_a * amount_b)`
+// on the first deposit, or the proportional share of the existing pool on later ones (the
+// smaller of the two ratios, so a lopsided deposit doesn't mint more than its worth).
+fn provide_liquidity(
+    deps: DepsMut,
+    info: MessageInfo,
+    assets: [Coin; 2],
+) -> Result<Response<sdk::Messages>, ContractError> {
+    assert_funds_match(&info, &assets)?;
+
+    let mut pool = POOL.load(deps.storage)?;
+    let (amount_a, amount_b) = pool_amounts(&pool, &assets)?;
+
+    if amount_a.is_zero() || amount_b.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    let minted_shares = if pool.total_shares.is_zero() {
+        isqrt(amount_a.full_mul(amount_b))
+            .try_into()
+            .map_err(|_| ContractError::Std(StdError::generic_err("share amount overflow")))?
+    } else {
+        std::cmp::min(
+            amount_a.multiply_ratio(pool.total_shares, pool.reserve_a),
+            amount_b.multiply_ratio(pool.total_shares, pool.reserve_b),
+        )
+    };
+
+    pool.reserve_a += amount_a;
+    pool.reserve_b += amount_b;
+    pool.total_shares += minted_shares;
+    POOL.save(deps.storage, &pool)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "provide_liquidity")
+        .add_attribute("shares_minted", minted_shares)
+        .add_message(sdk::Messages::AssetFTMsgMint {
+            denom: pool.share_denom.clone(),
+            amount: minted_shares,
+        })
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin {
+                denom: pool.share_denom,
+                amount: minted_shares,
+            }],
+        }))
+}
+
+// withdraw_liquidity burns `shares` and returns each asset's proportional share of the reserves.
+fn withdraw_liquidity(
+    deps: DepsMut,
+    info: MessageInfo,
+    shares: Uint128,
+) -> Result<Response<sdk::Messages>, ContractError> {
+    if shares == Uint128::zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    let mut pool = POOL.load(deps.storage)?;
+    assert_funds_match(
+        &info,
+        &[Coin {
+            denom: pool.share_denom.clone(),
+            amount: shares,
+        }],
+    )?;
+
+    let amount_a = shares.multiply_ratio(pool.reserve_a, pool.total_shares);
+    let amount_b = shares.multiply_ratio(pool.reserve_b, pool.total_shares);
+
+    pool.reserve_a -= amount_a;
+    pool.reserve_b -= amount_b;
+    pool.total_shares -= shares;
+    POOL.save(deps.storage, &pool)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "withdraw_liquidity")
+        .add_message(sdk::Messages::AssetFTMsgBurn {
+            denom: pool.share_denom,
+            amount: shares,
+        })
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![
+                Coin {
+                    denom: pool.denom_a,
+                    amount: amount_a,
+                },
+                Coin {
+                    denom: pool.denom_b,
+                    amount: amount_b,
+                },
+            ],
+        }))
+}
+
+// swap trades `offer` for the other pool asset using `out = reserve_out * offer_after_fee /
+// (reserve_in + offer_after_fee)`, the standard constant-product formula with the fee taken
+// out of the offer amount up front.
+fn swap(
+    deps: DepsMut,
+    info: MessageInfo,
+    offer: Coin,
+) -> Result<Response<sdk::Messages>, ContractError> {
+    assert_funds_match(&info, std::slice::from_ref(&offer))?;
+
+    let mut pool = POOL.load(deps.storage)?;
+
+    let (reserve_in, reserve_out, denom_out) = if offer.denom == pool.denom_a {
+        (pool.reserve_a, pool.reserve_b, pool.denom_b.clone())
+    } else if offer.denom == pool.denom_b {
+        (pool.reserve_b, pool.reserve_a, pool.denom_a.clone())
+    } else {
+        return Err(ContractError::CustomError {
+            val: format!("denom {} is not part of the pool", offer.denom),
+        });
+    };
+
+    let offer_after_fee = offer
+        .amount
+        .multiply_ratio(FEE_BPS_DENOMINATOR - pool.fee_bps as u128, FEE_BPS_DENOMINATOR);
+    let return_amount = reserve_out.multiply_ratio(offer_after_fee, reserve_in + offer_after_fee);
+
+    if offer.denom == pool.denom_a {
+        pool.reserve_a += offer.amount;
+        pool.reserve_b -= return_amount;
+    } else {
+        pool.reserve_b += offer.amount;
+        pool.reserve_a -= return_amount;
+    }
+    POOL.save(deps.storage, &pool)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "swap")
+        .add_attribute("offer_denom", offer.denom)
+        .add_attribute("return_amount", return_amount)
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin {
+                denom: denom_out,
+                amount: return_amount,
+            }],
+        }))
+}
+
+// pool_amounts matches the two deposited assets against the pool's denoms regardless of order.
+fn pool_amounts(pool: &Pool, assets: &[Coin; 2]) -> Result<(Uint128, Uint128), ContractError> {
+    let find = |denom: &str| -> Result<Uint128, ContractError> {
+        assets
+            .iter()
+            .find(|coin| coin.denom == denom)
+            .map(|coin| coin.amount)
+            .ok_or_else(|| ContractError::CustomError {
+                val: format!("missing asset {denom} for pool"),
+            })
+    };
+    Ok((find(&pool.denom_a)?, find(&pool.denom_b)?))
+}
+
+// isqrt computes the integer square root of a Uint256 via Newton's method.
+fn isqrt(value: Uint256) -> Uint256 {
+    if value.is_zero() {
+        return Uint256::zero();
+    }
+    let two = Uint256::from(2u128);
+    let mut x = value;
+    let mut y = (x + Uint256::one()) / two;
+    while y < x {
+        x = y;
+        y = (x + value / x) / two;
+    }
+    x
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, _env: Env, _msg: Reply) -> Result<Response, ContractError> {
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
     // After execution of each submessage this function is called.
     // Counter is incremented to confirm that callback is received.
 
@@ -112,7 +876,75 @@ pub fn reply(deps: DepsMut, _env: Env, _msg: Reply) -> Result<Response, Contract
         Ok(state)
     })?;
 
-    Ok(Response::new())
+    match msg.id {
+        ISSUE_CLASS_REPLY_ID => {
+            msg.result.into_result().map_err(ContractError::SubMsgFailure)?;
+            Ok(Response::new().add_attribute("method", "issue_class_reply"))
+        }
+        MINT_NFT_REPLY_ID => {
+            msg.result.into_result().map_err(ContractError::SubMsgFailure)?;
+            Ok(Response::new().add_attribute("method", "mint_nft_reply"))
+        }
+        POOL_SHARE_REPLY_ID => {
+            let share_denom = issued_denom(deps.storage, &env, &msg)?;
+            PENDING_SUBUNITS.remove(deps.storage, msg.id);
+
+            let pending = PENDING_POOL.load(deps.storage)?;
+            POOL.save(
+                deps.storage,
+                &Pool {
+                    denom_a: pending.denom_a,
+                    denom_b: pending.denom_b,
+                    share_denom: share_denom.clone(),
+                    reserve_a: Uint128::zero(),
+                    reserve_b: Uint128::zero(),
+                    total_shares: Uint128::zero(),
+                    fee_bps: pending.fee_bps,
+                },
+            )?;
+            PENDING_POOL.remove(deps.storage);
+
+            Ok(Response::new().add_attribute("pool_share_denom", share_denom))
+        }
+        id if id >= FIRST_ISSUE_REPLY_ID => {
+            let denom = issued_denom(deps.storage, &env, &msg)?;
+            ISSUED_DENOMS.save(deps.storage, id, &denom)?;
+            PENDING_SUBUNITS.remove(deps.storage, id);
+
+            let rates = PENDING_RATES.load(deps.storage, id)?;
+            TRANSFER_RATES.save(deps.storage, &denom, &rates)?;
+            PENDING_RATES.remove(deps.storage, id);
+
+            Ok(Response::new().add_attribute("issued_denom", denom))
+        }
+        id => Err(ContractError::SubMsgFailure(format!(
+            "unexpected reply id {id}"
+        ))),
+    }
+}
+
+// issued_denom recovers the denom created by a reply's originating `AssetFTMsgIssue`
+// submessage: the `asset` module returns it as a `MsgIssueResponse` in the submessage's
+// protobuf `data`, but if that's missing we fall back to reconstructing it the same way
+// the chain does, `subunit + "-" + contract address`.
+fn issued_denom(
+    storage: &dyn cosmwasm_std::Storage,
+    env: &Env,
+    msg: &Reply,
+) -> Result<String, ContractError> {
+    let response: SubMsgResult = msg.result.clone();
+    let response = response.into_result().map_err(ContractError::SubMsgFailure)?;
+
+    if let Some(data) = response.data {
+        if let Ok(issue_response) = MsgIssueResponse::decode(data.as_slice()) {
+            if !issue_response.denom.is_empty() {
+                return Ok(issue_response.denom);
+            }
+        }
+    }
+
+    let subunit = PENDING_SUBUNITS.load(storage, msg.id)?;
+    Ok(format!("{}-{}", subunit, env.contract.address))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -120,6 +952,14 @@ pub fn query(deps: Deps<sdk::Queries>, _env: Env, msg: QueryMsg) -> StdResult<Bi
     match msg {
         QueryMsg::GetCount {} => to_binary(&query_count(deps)?),
         QueryMsg::GetInfo { denom } => to_binary(&query_info(deps, denom)?),
+        QueryMsg::GetIssuedDenoms {} => to_binary(&query_issued_denoms(deps)?),
+        QueryMsg::GetBalance { account, denom } => to_binary(&query_balance(deps, account, denom)?),
+        QueryMsg::SimulateTransfer { denom, amount } => {
+            to_binary(&query_simulate_transfer(deps, denom, amount)?)
+        }
+        QueryMsg::GetNFTClass { class_id } => to_binary(&query_nft_class(deps, class_id)?),
+        QueryMsg::GetNFT { class_id, id } => to_binary(&query_nft(deps, class_id, id)?),
+        QueryMsg::GetPool {} => to_binary(&query_pool(deps)?),
     }
 }
 
@@ -135,8 +975,74 @@ fn query_info(deps: Deps<sdk::Queries>, denom: String) -> StdResult<InfoResponse
     Ok(InfoResponse { issuer: res.issuer })
 }
 
+fn query_issued_denoms(deps: Deps<sdk::Queries>) -> StdResult<IssuedDenomsResponse> {
+    let denoms = ISSUED_DENOMS
+        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .map(|item| item.map(|(_, denom)| denom))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(IssuedDenomsResponse { denoms })
+}
+
+fn query_balance(
+    deps: Deps<sdk::Queries>,
+    account: String,
+    denom: String,
+) -> StdResult<BalanceResponse> {
+    let request: QueryRequest<sdk::Queries> = QueryRequest::Bank(BankQuery::Balance {
+        address: account,
+        denom,
+    });
+    let res: cosmwasm_std::BalanceResponse = deps.querier.query(&request)?;
+    Ok(BalanceResponse {
+        amount: res.amount.amount,
+    })
+}
+
+fn query_simulate_transfer(
+    deps: Deps<sdk::Queries>,
+    denom: String,
+    amount: Uint128,
+) -> StdResult<SimulateTransferResponse> {
+    let rates = TRANSFER_RATES.load(deps.storage, &denom)?;
+    let (burn, commission) = transfer_fees(amount, &rates);
+    let net_received = amount
+        .checked_sub(burn)
+        .and_then(|remaining| remaining.checked_sub(commission))
+        .map_err(|_| StdError::generic_err("burn + commission exceeds amount"))?;
+    Ok(SimulateTransferResponse {
+        burn,
+        commission,
+        net_received,
+    })
+}
+
+fn query_nft_class(deps: Deps<sdk::Queries>, class_id: String) -> StdResult<NFTClassResponse> {
+    let request: QueryRequest<sdk::Queries> = sdk::Queries::AssetNFTGetClass { class_id }.into();
+    deps.querier.query(&request)
+}
+
+fn query_nft(deps: Deps<sdk::Queries>, class_id: String, id: String) -> StdResult<NFTResponse> {
+    let request: QueryRequest<sdk::Queries> = sdk::Queries::AssetNFTGetNFT { class_id, id }.into();
+    deps.querier.query(&request)
+}
+
+fn query_pool(deps: Deps<sdk::Queries>) -> StdResult<PoolResponse> {
+    let pool = POOL.load(deps.storage)?;
+    Ok(PoolResponse {
+        denom_a: pool.denom_a,
+        denom_b: pool.denom_b,
+        share_denom: pool.share_denom,
+        reserve_a: pool.reserve_a,
+        reserve_b: pool.reserve_b,
+        total_shares: pool.total_shares,
+    })
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
-pub struct InstantiateMsg {}
+pub struct InstantiateMsg {
+    // Compressed (33-byte) secp256k1 public key allowed to authorize `MintSigned` calls
+    pub authority_pubkey: Binary,
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -146,6 +1052,90 @@ pub enum ExecuteMsg {
         subunit: String,
         precision: u32,
         amount: Uint128,
+        features: Vec<u32>,
+        burn_rate: Decimal,
+        send_commission_rate: Decimal,
+    },
+    Mint {
+        denom: String,
+        amount: Uint128,
+    },
+    Burn {
+        denom: String,
+        amount: Uint128,
+    },
+    Freeze {
+        account: String,
+        denom: String,
+        amount: Uint128,
+    },
+    Unfreeze {
+        account: String,
+        denom: String,
+        amount: Uint128,
+    },
+    GloballyFreeze {
+        denom: String,
+    },
+    GloballyUnfreeze {
+        denom: String,
+    },
+    SetWhitelistedLimit {
+        account: String,
+        denom: String,
+        amount: Uint128,
+    },
+    Send {
+        to: String,
+        denom: String,
+        amount: Uint128,
+    },
+    MultiSend {
+        outputs: Vec<(String, Coin)>,
+    },
+    MintSigned {
+        recipient: String,
+        denom: String,
+        amount: Uint128,
+        nonce: u64,
+        signature: Binary,
+    },
+    IssueClass {
+        symbol: String,
+        name: String,
+        description: String,
+        uri: String,
+        features: Vec<u32>,
+    },
+    MintNFT {
+        class_id: String,
+        id: String,
+        uri: String,
+        data: Option<Binary>,
+    },
+    BurnNFT {
+        class_id: String,
+        id: String,
+    },
+    FreezeNFT {
+        class_id: String,
+        id: String,
+    },
+    CreatePool {
+        denom_a: String,
+        denom_b: String,
+        share_symbol: String,
+        share_subunit: String,
+        fee_bps: u64,
+    },
+    ProvideLiquidity {
+        assets: [Coin; 2],
+    },
+    WithdrawLiquidity {
+        shares: Uint128,
+    },
+    Swap {
+        offer: Coin,
     },
 }
 
@@ -156,6 +1146,18 @@ pub enum QueryMsg {
     GetCount {},
     // GetInfo returns information about fungible token
     GetInfo { denom: String },
+    // GetIssuedDenoms returns the denoms issued by `Issue` and confirmed by `reply` so far
+    GetIssuedDenoms {},
+    // GetBalance returns the balance of `denom` held by `account`
+    GetBalance { account: String, denom: String },
+    // SimulateTransfer previews the burn/commission/net split of transferring `amount` of `denom`
+    SimulateTransfer { denom: String, amount: Uint128 },
+    // GetNFTClass returns information about an asset-nft class
+    GetNFTClass { class_id: String },
+    // GetNFT returns information about a single NFT within a class
+    GetNFT { class_id: String, id: String },
+    // GetPool returns the reserves and share supply of the contract's pool
+    GetPool {},
 }
 
 // We define a custom struct for each query response
@@ -169,6 +1171,33 @@ pub struct InfoResponse {
     pub issuer: String,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct IssuedDenomsResponse {
+    pub denoms: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BalanceResponse {
+    pub amount: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SimulateTransferResponse {
+    pub burn: Uint128,
+    pub commission: Uint128,
+    pub net_received: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PoolResponse {
+    pub denom_a: String,
+    pub denom_b: String,
+    pub share_denom: String,
+    pub reserve_a: Uint128,
+    pub reserve_b: Uint128,
+    pub total_shares: Uint128,
+}
+
 #[derive(Error, Debug)]
 pub enum ContractError {
     #[error("{0}")]
@@ -180,8 +1209,20 @@ pub enum ContractError {
     #[error("Invalid zero amount")]
     InvalidZeroAmount {},
 
+    #[error("burn_rate + send_commission_rate must not exceed 1")]
+    InvalidRate {},
+
     #[error("Custom Error val: {val:?}")]
     CustomError { val: String },
+
+    #[error("submessage failed: {0}")]
+    SubMsgFailure(String),
+
+    #[error("Invalid signature")]
+    InvalidSignature {},
+
+    #[error("Nonce already used")]
+    NonceAlreadyUsed {},
     // Add any other custom errors you like here.
     // Look at https://docs.rs/thiserror/1.0.21/thiserror/ for details.
 }
@@ -0,0 +1,122 @@
+use cosmwasm_std::{Binary, CosmosMsg, CustomMsg, CustomQuery, Decimal, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// Messages mirrors the native message set the asset-ft and asset-nft modules accept.
+// Each variant is forwarded by the chain's wasm message parser to the matching
+// Cosmos SDK Msg, bypassing the need for a CW20-style wrapper contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Messages {
+    AssetFTMsgIssue {
+        symbol: String,
+        subunit: String,
+        precision: u32,
+        initial_amount: Uint128,
+        features: Vec<u32>,
+        burn_rate: Decimal,
+        send_commission_rate: Decimal,
+    },
+    AssetFTMsgMint {
+        denom: String,
+        amount: Uint128,
+    },
+    AssetFTMsgBurn {
+        denom: String,
+        amount: Uint128,
+    },
+    AssetFTMsgFreeze {
+        account: String,
+        denom: String,
+        amount: Uint128,
+    },
+    AssetFTMsgUnfreeze {
+        account: String,
+        denom: String,
+        amount: Uint128,
+    },
+    AssetFTMsgGloballyFreeze {
+        denom: String,
+    },
+    AssetFTMsgGloballyUnfreeze {
+        denom: String,
+    },
+    AssetFTMsgSetWhitelistedLimit {
+        account: String,
+        denom: String,
+        amount: Uint128,
+    },
+    AssetNFTMsgIssueClass {
+        symbol: String,
+        name: String,
+        description: String,
+        uri: String,
+        features: Vec<u32>,
+    },
+    AssetNFTMsgMint {
+        class_id: String,
+        id: String,
+        uri: String,
+        data: Option<Binary>,
+    },
+    AssetNFTMsgBurn {
+        class_id: String,
+        id: String,
+    },
+    AssetNFTMsgFreeze {
+        class_id: String,
+        id: String,
+    },
+}
+
+impl CustomMsg for Messages {}
+
+// Lets `Messages` be used anywhere a `CosmosMsg<Messages>` is expected, e.g. via
+// `Response::add_message`/`SubMsg::new`, by wrapping it as a custom chain message.
+impl From<Messages> for CosmosMsg<Messages> {
+    fn from(msg: Messages) -> Self {
+        CosmosMsg::Custom(msg)
+    }
+}
+
+// Queries mirrors the native query set the asset-ft and asset-nft modules expose.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Queries {
+    AssetFTGetToken { denom: String },
+    AssetNFTGetClass { class_id: String },
+    AssetNFTGetNFT { class_id: String, id: String },
+}
+
+impl CustomQuery for Queries {}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FungibleTokenResponse {
+    pub issuer: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct NFTClassResponse {
+    pub id: String,
+    pub issuer: String,
+    pub symbol: String,
+    pub name: String,
+    pub description: String,
+    pub uri: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct NFTResponse {
+    pub class_id: String,
+    pub id: String,
+    pub uri: String,
+    pub data: Option<Binary>,
+}
+
+// MsgIssueResponse mirrors the asset-ft module's `MsgIssueResponse` protobuf type, which is
+// what the chain puts in a submessage's `data` after an `AssetFTMsgIssue` executes.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgIssueResponse {
+    #[prost(string, tag = "1")]
+    pub denom: ::prost::alloc::string::String,
+}